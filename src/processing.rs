@@ -9,9 +9,10 @@ use std::fs::{read_to_string, File};
 use std::cell::Cell;
 use std::panic::PanicInfo;
 
+use std::collections::HashSet;
 use std::path::PathBuf;
+use std::sync::Mutex;
 
-// TODO support working in YAML but with Canonical JSON (RFC) output
 #[derive(Debug)]
 pub(crate) struct Environment {
     pub(crate) definitions: VariableSource,
@@ -21,6 +22,7 @@ pub(crate) struct Environment {
 #[derive(Debug)]
 pub(crate) enum TemplateFormat {
     Yaml,
+    Json,
     Text,
 }
 
@@ -30,6 +32,100 @@ pub(crate) struct Template {
     pub(crate) source_path: PathBuf,
 }
 
+// A single expansion-time problem, tagged with the breadcrumb path (mapping
+// keys / sequence indices) of where in the document it was found. Collected
+// instead of panicking so a whole file's problems can be reported at once.
+#[derive(Debug)]
+pub(crate) enum ExpansionError {
+    UnresolvedReference {
+        path: Vec<String>,
+        reference: String,
+        suggestion: Option<String>,
+    },
+    NonStringInterpolation {
+        path: Vec<String>,
+        reference: String,
+        value: Value,
+    },
+    NonMappingJsonTarget {
+        path: Vec<String>,
+        reference: String,
+        value: Value,
+    },
+    MissingMutationTarget {
+        path: Vec<String>,
+        description: String,
+    },
+}
+
+impl std::fmt::Display for ExpansionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (path, message) = match self {
+            ExpansionError::UnresolvedReference {
+                path,
+                reference,
+                suggestion,
+            } => (
+                path,
+                match suggestion {
+                    Some(suggestion) => {
+                        format!("unresolved reference \"{reference}\"; did you mean {suggestion}?")
+                    }
+                    None => format!("unresolved reference \"{reference}\""),
+                },
+            ),
+            ExpansionError::NonStringInterpolation {
+                path,
+                reference,
+                value,
+            } => (
+                path,
+                format!("attempted to interpolate non-string value \"{reference}\" ({value:?})"),
+            ),
+            ExpansionError::NonMappingJsonTarget {
+                path,
+                reference,
+                value,
+            } => (
+                path,
+                format!("\"{reference}\" did not resolve to a mapping for /json conversion ({value:?})"),
+            ),
+            ExpansionError::MissingMutationTarget { path, description } => (path, description.clone()),
+        };
+        if path.is_empty() {
+            write!(f, "{message}")
+        } else {
+            write!(f, "{}: {message}", path.join("/"))
+        }
+    }
+}
+
+// Everything `expand`/`lookup` observed while walking a template: hard errors
+// plus the two informational signals `--check` needs (references that were
+// allowed to be missing, and runtime values that were unexpectedly not).
+#[derive(Debug, Default)]
+pub(crate) struct Diagnostics {
+    pub(crate) errors: Vec<ExpansionError>,
+    pub(crate) expected_missing: Vec<(Vec<String>, String)>,
+    pub(crate) unexpectedly_hardcoded: Vec<(Vec<String>, String)>,
+}
+
+// Prints every accumulated problem for a template and fails the process if
+// there were any, the way a compiler batches up all its resolution errors
+// instead of stopping at the first.
+fn fail_on_errors(errors: &[ExpansionError]) {
+    if errors.is_empty() {
+        return;
+    }
+    for error in errors {
+        eprintln!("{error}");
+    }
+    panic!(
+        "{} problem(s) found while expanding this template",
+        errors.len()
+    );
+}
+
 fn mapping_value(val: &mut Value) -> Option<&mut Mapping> {
     if let Value::Mapping(ref mut m) = val {
         return Some(m);
@@ -89,44 +185,215 @@ impl TryNavigate for Value {
     }
 }
 
-fn apply_mutation(mutation: &MutationAction, content: &mut Value) {
+// Translates a glob (`*`, `**`, `?`) into an anchored regex. A pattern with no
+// glob metacharacters degenerates into a literal match, so plain filenames
+// keep working unchanged.
+fn glob_to_regex(pattern: &str) -> Regex {
+    let mut regex_str = String::from(r"\A");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => {
+                if chars.peek() == Some(&'*') {
+                    chars.next();
+                    regex_str.push_str(".*");
+                } else {
+                    regex_str.push_str("[^/]*");
+                }
+            }
+            '?' => regex_str.push_str("[^/]"),
+            other => regex_str.push_str(&regex::escape(&other.to_string())),
+        }
+    }
+    regex_str.push_str(r"\z");
+    Regex::new(&regex_str).unwrap_or_else(|_| panic!("Invalid filename_pattern glob: {pattern}"))
+}
+
+// A pattern with no `/` is matched against the template's filename alone
+// (so a literal like `application.yaml`, or a bare `*.yaml`, targets every
+// template with that name regardless of which directory it lives in); a
+// pattern containing `/` is matched against the full template path, which is
+// what lets `deploy/**/*.yaml` scope itself to a subtree.
+fn glob_match(pattern: &str, path: &str) -> bool {
+    if pattern.contains('/') {
+        glob_to_regex(pattern).is_match(path)
+    } else {
+        let filename = PathBuf::from(path)
+            .file_name()
+            .and_then(|f| f.to_str().map(str::to_string))
+            .unwrap_or_else(|| path.to_string());
+        glob_to_regex(pattern).is_match(&filename)
+    }
+}
+
+#[cfg(test)]
+mod glob_match_tests {
+    use super::glob_match;
+
+    #[test]
+    fn bare_star_matches_nested_templates() {
+        assert!(glob_match("*.yaml", "deploy/app.yaml"));
+        assert!(glob_match("*.yaml", "app.yaml"));
+    }
+
+    #[test]
+    fn bare_literal_matches_by_filename() {
+        assert!(glob_match("application.yaml", "templates/application.yaml"));
+        assert!(!glob_match("application.yaml", "templates/other.yaml"));
+    }
+
+    #[test]
+    fn slashed_pattern_matches_full_path() {
+        assert!(glob_match("deploy/**/*.yaml", "deploy/services/app.yaml"));
+        assert!(!glob_match("deploy/**/*.yaml", "other/services/app.yaml"));
+    }
+}
+
+lazy_static! {
+    static ref MATCHED_MUTATION_PATTERNS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+// Clears the matched-pattern bookkeeping so each run (normal or `--check`)
+// starts from a clean slate instead of inheriting matches from a previous
+// run in the same process.
+fn reset_mutation_pattern_tracking() {
+    MATCHED_MUTATION_PATTERNS.lock().unwrap().clear();
+}
+
+// Reports `filename_pattern`s that never matched a single template over the
+// course of the run, which almost always means a typo'd glob silently did
+// nothing. Call once after every template has been processed.
+pub(crate) fn warn_unmatched_mutation_patterns(environment: &Environment) {
+    let matched = MATCHED_MUTATION_PATTERNS.lock().unwrap();
+    for mutation in &environment.definitions.mutations {
+        if !matched.contains(&mutation.filename_pattern) {
+            eprintln!(
+                "WARN: Mutation filename_pattern \"{}\" did not match any template in this run.",
+                &mutation.filename_pattern
+            );
+        }
+    }
+}
+
+fn missing_target(path: &[String], description: String, diagnostics: &mut Diagnostics) {
+    diagnostics.errors.push(ExpansionError::MissingMutationTarget {
+        path: path.to_vec(),
+        description,
+    });
+}
+
+fn apply_mutation(mutation: &MutationAction, content: &mut Value, diagnostics: &mut Diagnostics) {
     match mutation {
         MutationAction::Add(path, Value::Mapping(new_entries)) => {
-            let current = mapping_value(content.navigate(path)).expect("urm");
+            let Some(target) = content.try_navigate(path) else {
+                return missing_target(path, "mutation target does not exist".to_string(), diagnostics);
+            };
+            let Some(current) = mapping_value(target) else {
+                return missing_target(path, "mutation target is not a mapping".to_string(), diagnostics);
+            };
             for (k, v) in new_entries.iter() {
                 let old_val = current.insert(k.clone(), v.clone());
                 if old_val.is_some() {
-                    panic!("Already had value at {path:?}")
+                    missing_target(path, format!("add mutation already had a value for {k:?}"), diagnostics);
                 }
             }
         }
         MutationAction::Add(path, Value::Sequence(new_elems)) => {
-            let current = sequence_value(content.navigate(path)).expect("urm");
+            let Some(target) = content.try_navigate(path) else {
+                return missing_target(path, "mutation target does not exist".to_string(), diagnostics);
+            };
+            let Some(current) = sequence_value(target) else {
+                return missing_target(path, "mutation target is not a sequence".to_string(), diagnostics);
+            };
             for v in new_elems.iter() {
                 current.push(v.clone());
             }
         }
-        MutationAction::Add(_path, _) => {
-            panic!("Add mutation is trying to add non-mapping, non-sequence values")
+        MutationAction::Add(path, _) => {
+            missing_target(
+                path,
+                "Add mutation is trying to add non-mapping, non-sequence values".to_string(),
+                diagnostics,
+            );
         }
         MutationAction::Remove(path) => {
-            mapping_value(content.navigate(&path[..(path.len() - 1)]))
-                .expect("not a mapping")
-                .remove(&path[path.len() - 1])
-                .unwrap_or_else(|| panic!("can't remove missing {:?}", &path));
+            let Some(target) = content.try_navigate(&path[..(path.len() - 1)]) else {
+                return missing_target(path, "mutation target does not exist".to_string(), diagnostics);
+            };
+            let Some(current) = mapping_value(target) else {
+                return missing_target(path, "mutation target is not a mapping".to_string(), diagnostics);
+            };
+            if current.remove(&path[path.len() - 1]).is_none() {
+                missing_target(path, "can't remove missing value".to_string(), diagnostics);
+            }
         }
         MutationAction::Replace(path, v) => {
-            let current =
-                mapping_value(content.navigate(&path[..(path.len() - 1)])).expect("not a mapping");
+            let Some(target) = content.try_navigate(&path[..(path.len() - 1)]) else {
+                return missing_target(path, "mutation target does not exist".to_string(), diagnostics);
+            };
+            let Some(current) = mapping_value(target) else {
+                return missing_target(path, "mutation target is not a mapping".to_string(), diagnostics);
+            };
             let old_val =
                 current.insert(Value::String(path[path.len() - 1].to_string()), v.clone());
             if old_val.is_none() {
-                panic!("Value to replace at {:?} did not exist", &path)
+                missing_target(path, "value to replace did not exist".to_string(), diagnostics);
             }
         }
     }
 }
 
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (n, m) = (a_chars.len(), b_chars.len());
+
+    let mut d = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=m {
+        d[0][j] = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = if a_chars[i - 1] == b_chars[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+        }
+    }
+    d[n][m]
+}
+
+// Scans the known definition names for ones that look like typos of `reference_name`,
+// so an `UnresolvedReference` error can point at the likely fix.
+fn did_you_mean(reference_name: &str, environment: &Environment) -> Option<String> {
+    let max_distance = (reference_name.chars().count() / 3).max(1);
+
+    let mut candidates: Vec<(usize, &String)> = environment
+        .definitions
+        .definitions
+        .keys()
+        .map(|key| (levenshtein_distance(reference_name, key), key))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    if candidates.is_empty() {
+        return None;
+    }
+    candidates.sort_by_key(|(distance, _)| *distance);
+    candidates.truncate(3);
+
+    Some(
+        candidates
+            .into_iter()
+            .map(|(_, key)| format!("\"{key}\""))
+            .collect::<Vec<_>>()
+            .join(" or "),
+    )
+}
+
 fn _lookup(reference_name: &str, environment: &Environment) -> Option<Value> {
     let maybe = environment.definitions.definitions.get(reference_name);
     match maybe {
@@ -143,7 +410,12 @@ fn _lookup(reference_name: &str, environment: &Environment) -> Option<Value> {
         Some(value) => Some(value.clone()),
     }
 }
-fn lookup(reference_name: &str, environment: &Environment) -> Option<Value> {
+fn lookup(
+    reference_name: &str,
+    environment: &Environment,
+    path: &[String],
+    diagnostics: &mut Diagnostics,
+) -> Option<Value> {
     let should_be_runtime_value = environment
         .expected_runtime_lookup_prefixes
         .iter()
@@ -153,19 +425,30 @@ fn lookup(reference_name: &str, environment: &Environment) -> Option<Value> {
     match _lookup(reference_name, environment) {
         None => {
             if should_be_runtime_value {
+                diagnostics
+                    .expected_missing
+                    .push((path.to_vec(), reference_name.to_string()));
                 None
             } else {
-                panic!("Couldn't find definition for {}", &reference_name)
+                diagnostics.errors.push(ExpansionError::UnresolvedReference {
+                    path: path.to_vec(),
+                    reference: reference_name.to_string(),
+                    suggestion: did_you_mean(reference_name, environment),
+                });
+                None
             }
         }
         Some(val) => {
             if should_be_runtime_value {
                 eprintln!(
                     "WARN: Runtime value \"{reference_name}\" was unexpectedly hardcoded."
-                )
+                );
+                diagnostics
+                    .unexpectedly_hardcoded
+                    .push((path.to_vec(), reference_name.to_string()));
             }
             if should_be_json {
-                let expanded_val = expand(val, environment);
+                let expanded_val = expand(val, environment, path, diagnostics);
                 if let Value::Mapping(m) = expanded_val {
                     Some(Value::String(
                         json_canon::to_string(&serde_json::to_value(m).unwrap()).unwrap(),
@@ -173,13 +456,15 @@ fn lookup(reference_name: &str, environment: &Environment) -> Option<Value> {
                 } else if let Value::String(s) = expanded_val {
                     Some(Value::String(s))
                 } else {
-                    panic!(
-                        "Received non-mapping value for /json conversion: {:?}",
-                        &expanded_val
-                    )
+                    diagnostics.errors.push(ExpansionError::NonMappingJsonTarget {
+                        path: path.to_vec(),
+                        reference: reference_name.to_string(),
+                        value: expanded_val,
+                    });
+                    None
                 }
             } else {
-                Some(expand(val, environment))
+                Some(expand(val, environment, path, diagnostics))
             }
         }
     }
@@ -191,44 +476,73 @@ lazy_static! {
         Regex::new(r"\A\s*\(\(\s*([^) ]*?)\s*\)\)\s*\z").unwrap();
 }
 
-fn expand_string(string: String, environment: &Environment) -> Value {
+fn expand_string(
+    string: String,
+    environment: &Environment,
+    path: &[String],
+    diagnostics: &mut Diagnostics,
+) -> Value {
     if let Some(captures) = FULL_MATCH_PATTERN.captures(&string) {
         let ref_name = captures.get(1).unwrap().as_str();
-        return lookup(ref_name, environment).unwrap_or(Value::String(string));
+        return lookup(ref_name, environment, path, diagnostics).unwrap_or(Value::String(string));
     }
     let substituted = VAR_SUBSTITUTION_PATTERN.replace_all(&string, |captures: &Captures| {
         let ref_name = captures.get(1).unwrap().as_str();
-        let val = lookup(ref_name, environment);
+        let val = lookup(ref_name, environment, path, diagnostics);
         match val {
             None => format!("(( {ref_name} ))"),
             Some(Value::Number(n)) => format!("{n}"),
             Some(Value::String(str)) => str,
-            Some(_) => panic!(
-                "Attempted to interpolate non-string value \"{ref_name}\" ({val:?})"
-            ),
+            Some(other) => {
+                diagnostics.errors.push(ExpansionError::NonStringInterpolation {
+                    path: path.to_vec(),
+                    reference: ref_name.to_string(),
+                    value: other,
+                });
+                format!("(( {ref_name} ))")
+            }
         }
     });
     Value::String(substituted.to_string())
 }
 
-fn expand(content: Value, environment: &Environment) -> Value {
+fn expand(
+    content: Value,
+    environment: &Environment,
+    path: &[String],
+    diagnostics: &mut Diagnostics,
+) -> Value {
     match content {
         Value::Null => Value::Null,
         Value::Bool(a) => Value::Bool(a),
         Value::Number(a) => Value::Number(a),
-        Value::String(str) => expand_string(str, environment),
-        Value::Sequence(seq) => {
-            Value::Sequence(seq.into_iter().map(|v| expand(v, environment)).collect())
-        }
+        Value::String(str) => expand_string(str, environment, path, diagnostics),
+        Value::Sequence(seq) => Value::Sequence(
+            seq.into_iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    let mut child_path = path.to_vec();
+                    child_path.push(i.to_string());
+                    expand(v, environment, &child_path, diagnostics)
+                })
+                .collect(),
+        ),
         Value::Mapping(map) => {
             let mut stuff = map
                 .into_iter()
-                .map(|(k, v)| (expand(k, environment), v))
+                .map(|(k, v)| (expand(k, environment, path, diagnostics), v))
                 .collect::<Vec<_>>();
             stuff.sort_by_key(|(k, _v)| string_value(k));
             let stuff = stuff
                 .into_iter()
-                .map(|(k, v)| (k, expand(v, environment)))
+                .map(|(k, v)| {
+                    let mut child_path = path.to_vec();
+                    if let Some(key) = string_value(&k) {
+                        child_path.push(key);
+                    }
+                    let expanded = expand(v, environment, &child_path, diagnostics);
+                    (k, expanded)
+                })
                 .collect();
             Value::Mapping(stuff)
         }
@@ -273,28 +587,98 @@ fn with_error_catcher<T>(output_path: String, processor: &dyn Fn()->T) -> T {
 pub(crate) fn process_text(template: &Template, environment: &Environment, output_path: String) -> String {
     with_error_catcher(output_path, &|| {
         let text = read_to_string(&template.source_path).unwrap();
-        string_value(&expand_string(text, environment))
-            .expect("Text template somehow expanded to a non-string value")
+        let mut diagnostics = Diagnostics::default();
+        let content = expand_string(text, environment, &[], &mut diagnostics);
+        fail_on_errors(&diagnostics.errors);
+        string_value(&content).expect("Text template somehow expanded to a non-string value")
     })
 }
 
-pub(crate) fn process_yaml(template: &Template, environment: &Environment, output_path: String) -> Value {
-    with_error_catcher(output_path, &|| {
-        let filename = template.source_path.file_name().unwrap().to_str().unwrap();
-        let mut content: Value =
-            serde_yaml::from_reader(File::open(&template.source_path).unwrap()).unwrap();
+// Parses the source as YAML, applies whichever mutations match this template's
+// path, and expands all `(( ref ))` substitutions. Shared by `process_yaml`
+// and `process_json`, which only differ in how they serialize the result.
+fn expand_template(
+    template: &Template,
+    environment: &Environment,
+    diagnostics: &mut Diagnostics,
+) -> Value {
+    let path = template.source_path.to_str().unwrap();
+    let mut content: Value =
+        serde_yaml::from_reader(File::open(&template.source_path).unwrap()).unwrap();
 
-        for mutation in &environment.definitions.mutations {
-            if mutation.filename_pattern == filename {
-                apply_mutation(&mutation.action, &mut content);
-            }
+    for mutation in &environment.definitions.mutations {
+        if glob_match(&mutation.filename_pattern, path) {
+            MATCHED_MUTATION_PATTERNS
+                .lock()
+                .unwrap()
+                .insert(mutation.filename_pattern.clone());
+            apply_mutation(&mutation.action, &mut content, diagnostics);
         }
-        let mut content = expand(content, environment);
+    }
+    expand(content, environment, &[], diagnostics)
+}
+
+pub(crate) fn process_yaml(template: &Template, environment: &Environment, output_path: String) -> Value {
+    with_error_catcher(output_path, &|| {
+        let mut diagnostics = Diagnostics::default();
+        let mut content = expand_template(template, environment, &mut diagnostics);
+        fail_on_errors(&diagnostics.errors);
         postprocess_yaml(&mut content);
         content
     })
 }
 
+// RFC 8785 canonical JSON output for templates authored in the friendlier
+// YAML syntax: same expand + mutation pipeline as `process_yaml`, but
+// serialized as a single deterministic, diff-stable JSON document.
+pub(crate) fn process_json(template: &Template, environment: &Environment, output_path: String) -> String {
+    with_error_catcher(output_path, &|| {
+        let mut diagnostics = Diagnostics::default();
+        let content = expand_template(template, environment, &mut diagnostics);
+        fail_on_errors(&diagnostics.errors);
+        json_canon::to_string(&serde_json::to_value(content).unwrap()).unwrap()
+    })
+}
+
+pub(crate) enum ProcessedTemplate {
+    Yaml(Value),
+    Json(String),
+    Text(String),
+}
+
+// Runs every template through the matching `process_*` function and, once
+// the whole batch is done, reports any mutation `filename_pattern` that
+// never matched a template. This is the normal (non-`--check`) call site for
+// that warning, so a typo'd glob doesn't go unnoticed just because nobody
+// ran `--check`.
+pub(crate) fn process_templates(
+    templates: &[Template],
+    environment: &Environment,
+    output_path_for: impl Fn(&Template) -> String,
+) -> Vec<(PathBuf, ProcessedTemplate)> {
+    reset_mutation_pattern_tracking();
+    let results = templates
+        .iter()
+        .map(|template| {
+            let output_path = output_path_for(template);
+            let processed = match template.format {
+                TemplateFormat::Yaml => {
+                    ProcessedTemplate::Yaml(process_yaml(template, environment, output_path))
+                }
+                TemplateFormat::Json => {
+                    ProcessedTemplate::Json(process_json(template, environment, output_path))
+                }
+                TemplateFormat::Text => {
+                    ProcessedTemplate::Text(process_text(template, environment, output_path))
+                }
+            };
+            (template.source_path.clone(), processed)
+        })
+        .collect();
+    warn_unmatched_mutation_patterns(environment);
+    results
+}
+
 fn postprocess_yaml(_yaml_config: &mut Value) {
     // i've left this here as an example of doing this kind of thing
     // it can be nice to work around frameworks which have an annoying config format
@@ -317,3 +701,58 @@ fn postprocess_yaml(_yaml_config: &mut Value) {
     //     }
     // }
 }
+
+// Dry-run check: loads a template and runs the same expand/mutation pipeline
+// as `process_yaml`/`process_text`, but never writes anything out. Used by
+// `--check` to validate a whole set of templates in one pass.
+fn check_template(template: &Template, environment: &Environment) -> Diagnostics {
+    let output_path = template.source_path.to_string_lossy().to_string();
+    with_error_catcher(output_path, &|| {
+        let mut diagnostics = Diagnostics::default();
+        match template.format {
+            TemplateFormat::Yaml | TemplateFormat::Json => {
+                expand_template(template, environment, &mut diagnostics);
+            }
+            TemplateFormat::Text => {
+                let text = read_to_string(&template.source_path).unwrap();
+                expand_string(text, environment, &[], &mut diagnostics);
+            }
+        }
+        diagnostics
+    })
+}
+
+// Runs `check_template` over every template and prints a report: expected-missing
+// references (covered by `expected_runtime_lookup_prefixes`) and unexpectedly
+// hardcoded runtime values are informational, genuine unresolved references are
+// errors. Returns whether the whole run is clean, so the caller can exit nonzero
+// in CI without ever producing output files.
+pub(crate) fn run_check(templates: &[Template], environment: &Environment) -> bool {
+    reset_mutation_pattern_tracking();
+    let mut ok = true;
+
+    for template in templates {
+        let display_path = template.source_path.display();
+        let diagnostics = check_template(template, environment);
+
+        for (ref_path, reference) in &diagnostics.expected_missing {
+            println!(
+                "{display_path}: {}: expected-missing reference \"{reference}\"",
+                ref_path.join("/")
+            );
+        }
+        for (ref_path, reference) in &diagnostics.unexpectedly_hardcoded {
+            println!(
+                "{display_path}: {}: runtime value \"{reference}\" was unexpectedly hardcoded",
+                ref_path.join("/")
+            );
+        }
+        for error in &diagnostics.errors {
+            println!("{display_path}: {error}");
+            ok = false;
+        }
+    }
+
+    warn_unmatched_mutation_patterns(environment);
+    ok
+}